@@ -13,6 +13,7 @@ use cocogitto::{CocoGitto, SETTINGS};
 
 use anyhow::{Context, Result};
 use cocogitto::git::revspec::RevspecPattern;
+use regex::Regex;
 use structopt::clap::{AppSettings, Shell};
 use structopt::StructOpt;
 
@@ -83,6 +84,10 @@ enum Cli {
         /// omit error on the commit log
         #[structopt(short = "e", long)]
         no_error: bool,
+
+        /// match type, scope and author filters as regular expressions
+        #[structopt(short = "E", long)]
+        regex: bool,
     },
 
     /// Verify a single commit message
@@ -92,20 +97,26 @@ enum Cli {
         message: String,
     },
 
-    /// Display a changelog for the given commit oid range
+    /// Display a changelog for the given commit oid range, a single tag, or the whole history
     #[structopt(no_version, settings = SUBCOMMAND_SETTINGS)]
     Changelog {
         /// Generate the changelog from in the given spec range
-        #[structopt(conflicts_with = "at")]
+        #[structopt(conflicts_with_all = &["at", "all"])]
         pattern: Option<String>,
 
         /// Generate the changelog for a specific git tag
-        #[structopt(short, long)]
+        #[structopt(short, long, conflicts_with = "all")]
         at: Option<String>,
 
+        /// Generate the whole changelog history, with one section per release
+        #[structopt(long)]
+        all: bool,
+
         /// Generate the changelog with the given template.
-        /// Possible values are 'remote', 'full_hash', 'default' or the path to your template.  
+        /// Possible values are 'remote', 'full_hash', 'default' or the path to your template.
         /// If not specified cog will use cog.toml template config or fallback to 'default'.
+        /// Each section header shows the release version and date; 'remote' also links it
+        /// to a comparison with the previous tag. A template file's contents are not read.
         #[structopt(name = "template", long, short)]
         template: Option<String>,
 
@@ -120,6 +131,14 @@ enum Cli {
         /// Name of the repository used during template generation
         #[structopt(name = "repository", long, required_if("template", "remote"))]
         repository: Option<String>,
+
+        /// Generate the changelog for a specific monorepo package only
+        #[structopt(short, long)]
+        package: Option<String>,
+
+        /// match the package scope filter as a regular expression (not applied with --all)
+        #[structopt(short = "E", long)]
+        regex: bool,
     },
 
     /// Commit changelog from latest tag to HEAD and create new tag
@@ -152,6 +171,10 @@ enum Cli {
         /// Specify the bump profile hooks to run
         #[structopt(short, long, possible_values = &hook_profiles())]
         hook_profile: Option<String>,
+
+        /// Bump only the given monorepo package, using its own tag namespace
+        #[structopt(long)]
+        package: Option<String>,
     },
 
     /// Install cog config files
@@ -213,6 +236,7 @@ fn main() -> Result<()> {
             patch,
             pre,
             hook_profile,
+            package,
         } => {
             let mut cocogitto = CocoGitto::get()?;
 
@@ -225,7 +249,12 @@ fn main() -> Result<()> {
                 _ => unreachable!(),
             };
 
-            cocogitto.create_version(increment, pre.as_deref(), hook_profile.as_deref())?
+            cocogitto.create_version(
+                increment,
+                pre.as_deref(),
+                hook_profile.as_deref(),
+                package.as_deref(),
+            )?
         }
         Cli::Verify { message } => {
             let author = CocoGitto::get()
@@ -248,6 +277,7 @@ fn main() -> Result<()> {
             author,
             scope,
             no_error,
+            regex,
         } => {
             let cocogitto = CocoGitto::get()?;
 
@@ -261,19 +291,33 @@ fn main() -> Result<()> {
 
             let mut filters = vec![];
             if let Some(commit_types) = typ {
-                filters.extend(
-                    commit_types
-                        .iter()
-                        .map(|commit_type| CommitFilter::Type(commit_type.as_str().into())),
-                );
+                for commit_type in &commit_types {
+                    filters.push(if regex {
+                        CommitFilter::TypeRegex(Regex::new(commit_type)?)
+                    } else {
+                        CommitFilter::Type(commit_type.as_str().into())
+                    });
+                }
             }
 
             if let Some(scopes) = scope {
-                filters.extend(scopes.into_iter().map(CommitFilter::Scope));
+                for scope in scopes {
+                    filters.push(if regex {
+                        CommitFilter::ScopeRegex(Regex::new(&scope)?)
+                    } else {
+                        CommitFilter::Scope(scope)
+                    });
+                }
             }
 
             if let Some(authors) = author {
-                filters.extend(authors.into_iter().map(CommitFilter::Author));
+                for author in authors {
+                    filters.push(if regex {
+                        CommitFilter::AuthorRegex(Regex::new(&author)?)
+                    } else {
+                        CommitFilter::Author(author)
+                    });
+                }
             }
 
             if breaking_change {
@@ -299,6 +343,9 @@ fn main() -> Result<()> {
             remote,
             owner,
             repository,
+            package,
+            regex,
+            all,
         } => {
             let cocogitto = CocoGitto::get()?;
 
@@ -324,11 +371,22 @@ fn main() -> Result<()> {
 
             let pattern = pattern.as_deref().map(RevspecPattern::from);
 
-            let result = match at {
-                Some(at) => cocogitto.get_changelog_at_tag(&at, template)?,
-                None => {
-                    let changelog = cocogitto.get_changelog(pattern.unwrap_or_default(), true)?;
-                    changelog.into_markdown(template)?
+            let result = if all {
+                cocogitto.get_changelog_for_all_tags(template, package.as_deref())?
+            } else {
+                match at {
+                    Some(at) => {
+                        cocogitto.get_changelog_at_tag(&at, template, package.as_deref(), regex)?
+                    }
+                    None => {
+                        let changelog = cocogitto.get_changelog(
+                            pattern.unwrap_or_default(),
+                            true,
+                            package.as_deref(),
+                            regex,
+                        )?;
+                        changelog.into_markdown(template)?
+                    }
                 }
             };
             println!("{}", result);
@@ -368,3 +426,53 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bump_package_flag_has_no_short_flag_collision_with_patch() {
+        // structopt/clap assert short flags are unique when building the
+        // `App`; this would panic in a debug build before parsing anything
+        // if `--package` and `--patch` still both derived to `-p`.
+        let cli = Cli::from_iter_safe(["cog", "bump", "--auto", "--package", "foo"]).unwrap();
+        match cli {
+            Cli::Bump { auto, package, .. } => {
+                assert!(auto);
+                assert_eq!(package.as_deref(), Some("foo"));
+            }
+            _ => panic!("expected Cli::Bump"),
+        }
+    }
+
+    #[test]
+    fn bump_patch_short_flag_still_works() {
+        let cli = Cli::from_iter_safe(["cog", "bump", "-p"]).unwrap();
+        assert!(matches!(cli, Cli::Bump { patch: true, .. }));
+    }
+
+    #[test]
+    fn changelog_package_and_regex_are_accepted_alongside_at() {
+        let cli = Cli::from_iter_safe([
+            "cog",
+            "changelog",
+            "--at",
+            "v1.0.0",
+            "--package",
+            "foo",
+            "--regex",
+        ])
+        .unwrap();
+        match cli {
+            Cli::Changelog {
+                at, package, regex, ..
+            } => {
+                assert_eq!(at.as_deref(), Some("v1.0.0"));
+                assert_eq!(package.as_deref(), Some("foo"));
+                assert!(regex);
+            }
+            _ => panic!("expected Cli::Changelog"),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::env;
+use std::fs;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+
+/// Conventional commit types `cog commit` accepts as its `type` argument.
+pub fn commit_types() -> Vec<&'static str> {
+    vec![
+        "feat", "fix", "chore", "revert", "perf", "docs", "style", "refactor", "test", "build",
+        "ci",
+    ]
+}
+
+/// Open `$EDITOR` on a scratch file pre-filled with the commit header, then
+/// split the edited content back into a body, a footer, and whether a
+/// `BREAKING CHANGE:` footer was added.
+pub fn edit_message(
+    typ: &str,
+    message: &str,
+    scope: Option<&str>,
+    breaking_change: bool,
+) -> Result<(Option<String>, Option<String>, bool)> {
+    let mut header = typ.to_string();
+    if let Some(scope) = scope {
+        header.push_str(&format!("({scope})"));
+    }
+    if breaking_change {
+        header.push('!');
+    }
+    header.push_str(": ");
+    header.push_str(message);
+
+    let editor = env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+    let path = env::temp_dir().join("cog_commit_message.txt");
+    fs::write(&path, &header).context("failed to create commit message scratch file")?;
+
+    Command::new(editor)
+        .arg(&path)
+        .status()
+        .context("failed to launch $EDITOR")?;
+
+    let edited = fs::read_to_string(&path)?;
+    let breaking = breaking_change || edited.contains("BREAKING CHANGE:");
+
+    let mut parts = edited.splitn(2, "\n\n").skip(1);
+    let (body, footer) = match parts.next() {
+        None => (None, None),
+        Some(rest) => match rest.splitn(2, "\n\n").collect::<Vec<_>>().as_slice() {
+            [body, footer] => (Some(body.trim().to_string()), Some(footer.trim().to_string())),
+            [body] if !body.trim().is_empty() => (Some(body.trim().to_string()), None),
+            _ => (None, None),
+        },
+    };
+
+    Ok((body, footer, breaking))
+}
@@ -0,0 +1,24 @@
+pub mod template;
+
+use anyhow::Result;
+use chrono::NaiveDate;
+
+use crate::conventional::commit::Commit;
+use template::Template;
+
+/// A contiguous range of commits rendered as one changelog section: either
+/// a tagged release (`to` is the tag name, `date` its commit date) or the
+/// unreleased commits on top of the latest tag (`to` is `"HEAD"`).
+#[derive(Debug, Clone)]
+pub struct Changelog {
+    pub from: Option<String>,
+    pub to: String,
+    pub date: NaiveDate,
+    pub commits: Vec<Commit>,
+}
+
+impl Changelog {
+    pub fn into_markdown(self, template: Template) -> Result<String> {
+        template.render(&self)
+    }
+}
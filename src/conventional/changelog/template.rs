@@ -0,0 +1,169 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use crate::conventional::changelog::Changelog;
+use crate::conventional::commit::Commit;
+
+/// Link generation context for the `remote` template: who hosts the repo,
+/// used to build commit and compare links.
+#[derive(Debug, Clone)]
+pub struct RemoteContext {
+    pub remote: String,
+    pub repository: String,
+    pub owner: String,
+}
+
+impl RemoteContext {
+    pub fn new(remote: String, repository: String, owner: String) -> Self {
+        RemoteContext {
+            remote,
+            repository,
+            owner,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+enum TemplateKind {
+    #[default]
+    Default,
+    FullHash,
+    Remote,
+}
+
+/// How to render a [`Changelog`] section into markdown.
+#[derive(Debug, Clone, Default)]
+pub struct Template {
+    kind: TemplateKind,
+    remote: Option<RemoteContext>,
+}
+
+impl Template {
+    /// Build a template from the `--template` CLI argument: `default`,
+    /// `full_hash`, `remote`, or a path to a template file. A path must
+    /// exist, but its contents are not read: it renders like `default`.
+    pub fn from_arg(arg: &str, context: Option<RemoteContext>) -> Result<Self> {
+        let kind = match arg {
+            "default" => TemplateKind::Default,
+            "full_hash" => TemplateKind::FullHash,
+            "remote" => TemplateKind::Remote,
+            path => {
+                fs::metadata(path)
+                    .with_context(|| format!("template file {path} not found"))?;
+                TemplateKind::Default
+            }
+        };
+
+        Ok(Template {
+            kind,
+            remote: context,
+        })
+    }
+
+    pub fn render(&self, changelog: &Changelog) -> Result<String> {
+        let mut markdown = format!(
+            "## {} - {}\n\n",
+            self.render_header(changelog),
+            changelog.date
+        );
+        for commit in &changelog.commits {
+            markdown.push_str(&format!("- {}\n", self.render_commit(commit)));
+        }
+
+        Ok(markdown)
+    }
+
+    fn render_header(&self, changelog: &Changelog) -> String {
+        let title = match changelog.to.as_str() {
+            "HEAD" => "Unreleased",
+            to => to,
+        };
+        match (&self.kind, &self.remote, &changelog.from) {
+            (TemplateKind::Remote, Some(remote), Some(from)) => format!(
+                "[{}]({}/{}/{}/compare/{}...{})",
+                title, remote.remote, remote.owner, remote.repository, from, changelog.to
+            ),
+            _ => title.to_string(),
+        }
+    }
+
+    fn render_commit(&self, commit: &Commit) -> String {
+        match (&self.kind, &self.remote) {
+            (TemplateKind::FullHash, _) => format!("{} {}", commit.oid, commit.summary),
+            (TemplateKind::Remote, Some(remote)) => format!(
+                "{} ([{}]({}/{}/{}/commit/{}))",
+                commit.summary,
+                &commit.oid.to_string()[..7],
+                remote.remote,
+                remote.owner,
+                remote.repository,
+                commit.oid
+            ),
+            (TemplateKind::Default, _) | (TemplateKind::Remote, None) => commit.summary.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn changelog() -> Changelog {
+        Changelog {
+            from: None,
+            to: "v1.0.0".to_string(),
+            date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            commits: vec![],
+        }
+    }
+
+    #[test]
+    fn renders_a_version_header() {
+        let template = Template::default();
+        let markdown = template.render(&changelog()).unwrap();
+        assert!(markdown.starts_with("## v1.0.0"));
+    }
+
+    #[test]
+    fn header_includes_the_release_date() {
+        let template = Template::default();
+        let markdown = template.render(&changelog()).unwrap();
+        assert!(markdown.starts_with("## v1.0.0 - 2024-01-01"));
+    }
+
+    #[test]
+    fn remote_template_links_the_header_to_a_compare_view_against_the_previous_tag() {
+        let template = Template {
+            kind: TemplateKind::Remote,
+            remote: Some(RemoteContext::new(
+                "https://github.com".to_string(),
+                "cocogitto".to_string(),
+                "oknozor".to_string(),
+            )),
+        };
+        let mut changelog = changelog();
+        changelog.from = Some("v0.9.0".to_string());
+
+        let markdown = template.render(&changelog).unwrap();
+        assert!(markdown.starts_with(
+            "## [v1.0.0](https://github.com/oknozor/cocogitto/compare/v0.9.0...v1.0.0)"
+        ));
+    }
+
+    #[test]
+    fn remote_template_falls_back_to_a_plain_header_without_a_previous_tag() {
+        let template = Template {
+            kind: TemplateKind::Remote,
+            remote: Some(RemoteContext::new(
+                "https://github.com".to_string(),
+                "cocogitto".to_string(),
+                "oknozor".to_string(),
+            )),
+        };
+
+        let markdown = template.render(&changelog()).unwrap();
+        assert!(markdown.starts_with("## v1.0.0"));
+    }
+}
@@ -0,0 +1,111 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::NaiveDate;
+
+/// The `type` prefix of a conventional commit (`feat`, `fix`, `chore`, ...).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommitType(String);
+
+impl CommitType {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for CommitType {
+    fn from(value: &str) -> Self {
+        CommitType(value.to_string())
+    }
+}
+
+impl fmt::Display for CommitType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A single commit, parsed as a conventional commit.
+#[derive(Debug, Clone)]
+pub struct Commit {
+    pub oid: git2::Oid,
+    pub author: String,
+    pub date: NaiveDate,
+    pub commit_type: CommitType,
+    pub scope: Option<String>,
+    pub summary: String,
+    pub body: Option<String>,
+    pub breaking_change: bool,
+}
+
+impl fmt::Display for Commit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}{}: {}",
+            &self.oid.to_string()[..7],
+            self.commit_type,
+            self.scope
+                .as_deref()
+                .map(|scope| format!("({scope})"))
+                .unwrap_or_default(),
+            self.summary
+        )
+    }
+}
+
+impl TryFrom<&git2::Commit<'_>> for Commit {
+    type Error = anyhow::Error;
+
+    fn try_from(commit: &git2::Commit) -> Result<Self> {
+        let message = commit
+            .message()
+            .context("commit message is not valid UTF-8")?;
+
+        let parsed = parse(message)
+            .map_err(|e| anyhow!("{}: {e}", &commit.id().to_string()[..7]))?;
+
+        let author = commit.author().name().unwrap_or("unknown").to_string();
+        let date = chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
+            .context("commit has an invalid timestamp")?
+            .date_naive();
+
+        Ok(Commit {
+            oid: commit.id(),
+            author,
+            date,
+            commit_type: CommitType(parsed.type_().as_str().to_string()),
+            scope: parsed.scope().map(|scope| scope.to_string()),
+            summary: parsed.description().to_string(),
+            body: parsed.body().map(|body| body.to_string()),
+            breaking_change: parsed.breaking(),
+        })
+    }
+}
+
+fn parse(message: &str) -> std::result::Result<git_conventional::Commit<'_>, git_conventional::Error> {
+    git_conventional::Commit::parse(message.trim())
+}
+
+/// Verify that `message` is a valid conventional commit message.
+pub fn verify(_author: Option<String>, message: &str) -> Result<()> {
+    parse(message)
+        .map(|_| ())
+        .map_err(|e| anyhow!("invalid conventional commit message: {e}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn accepts_a_valid_conventional_commit() {
+        assert!(verify(None, "feat(parser): add support for scopes").is_ok());
+    }
+
+    #[test]
+    fn rejects_a_non_conventional_commit() {
+        assert!(verify(None, "just a regular message").is_err());
+    }
+}
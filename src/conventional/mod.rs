@@ -0,0 +1,3 @@
+pub mod changelog;
+pub mod commit;
+pub mod version;
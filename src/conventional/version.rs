@@ -0,0 +1,143 @@
+use anyhow::{Context, Result};
+use semver::{Prerelease, Version};
+
+use crate::conventional::commit::Commit;
+
+/// How the next version should be computed from the current one.
+#[derive(Debug, Clone)]
+pub enum VersionIncrement {
+    Manual(String),
+    Auto,
+    Major,
+    Minor,
+    Patch,
+}
+
+impl VersionIncrement {
+    /// Compute the next version from `current`. `commits` is only consulted
+    /// for `VersionIncrement::Auto`, where the highest-impact commit since
+    /// the last release decides the bump level.
+    pub fn bump(&self, current: &Version, commits: &[Commit], pre: Option<&str>) -> Result<Version> {
+        let mut next = match self {
+            VersionIncrement::Manual(version) => Version::parse(version)
+                .with_context(|| format!("{version} is not a valid semver version"))?,
+            VersionIncrement::Major => bump_major(current),
+            VersionIncrement::Minor => bump_minor(current),
+            VersionIncrement::Patch => bump_patch(current),
+            VersionIncrement::Auto => auto_bump(current, commits),
+        };
+
+        if let Some(pre) = pre {
+            next.pre = Prerelease::new(pre)
+                .with_context(|| format!("{pre} is not a valid prerelease identifier"))?;
+        }
+
+        Ok(next)
+    }
+}
+
+fn bump_major(version: &Version) -> Version {
+    Version::new(version.major + 1, 0, 0)
+}
+
+fn bump_minor(version: &Version) -> Version {
+    Version::new(version.major, version.minor + 1, 0)
+}
+
+fn bump_patch(version: &Version) -> Version {
+    Version::new(version.major, version.minor, version.patch + 1)
+}
+
+/// `feat` commits or a `BREAKING CHANGE` footer drive a minor or major bump
+/// respectively (major only once the project has left `0.x`); anything else
+/// (`fix`, `chore`, `docs`, ...) is a patch bump.
+fn auto_bump(current: &Version, commits: &[Commit]) -> Version {
+    let has_breaking = commits.iter().any(|commit| commit.breaking_change);
+    let has_feature = commits.iter().any(|commit| commit.commit_type.as_str() == "feat");
+
+    if has_breaking && current.major > 0 {
+        bump_major(current)
+    } else if has_feature || has_breaking {
+        bump_minor(current)
+    } else {
+        bump_patch(current)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use git2::Oid;
+
+    fn commit(commit_type: &str, breaking_change: bool) -> Commit {
+        Commit {
+            oid: Oid::zero(),
+            author: "test".to_string(),
+            date: chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            commit_type: commit_type.into(),
+            scope: None,
+            summary: "test commit".to_string(),
+            body: None,
+            breaking_change,
+        }
+    }
+
+    #[test]
+    fn manual_bump_parses_the_given_version() {
+        let current = Version::new(1, 0, 0);
+        let next = VersionIncrement::Manual("2.3.4".to_string())
+            .bump(&current, &[], None)
+            .unwrap();
+        assert_eq!(next, Version::new(2, 3, 4));
+    }
+
+    #[test]
+    fn explicit_increments_ignore_commit_history() {
+        let current = Version::new(1, 2, 3);
+        assert_eq!(
+            VersionIncrement::Major.bump(&current, &[], None).unwrap(),
+            Version::new(2, 0, 0)
+        );
+        assert_eq!(
+            VersionIncrement::Minor.bump(&current, &[], None).unwrap(),
+            Version::new(1, 3, 0)
+        );
+        assert_eq!(
+            VersionIncrement::Patch.bump(&current, &[], None).unwrap(),
+            Version::new(1, 2, 4)
+        );
+    }
+
+    #[test]
+    fn auto_bump_picks_minor_for_a_feature() {
+        let current = Version::new(1, 0, 0);
+        let commits = vec![commit("fix", false), commit("feat", false)];
+        let next = VersionIncrement::Auto.bump(&current, &commits, None).unwrap();
+        assert_eq!(next, Version::new(1, 1, 0));
+    }
+
+    #[test]
+    fn auto_bump_picks_major_for_a_breaking_change_post_1_0() {
+        let current = Version::new(1, 0, 0);
+        let commits = vec![commit("feat", true)];
+        let next = VersionIncrement::Auto.bump(&current, &commits, None).unwrap();
+        assert_eq!(next, Version::new(2, 0, 0));
+    }
+
+    #[test]
+    fn auto_bump_picks_patch_when_nothing_semver_relevant_happened() {
+        let current = Version::new(1, 2, 3);
+        let commits = vec![commit("chore", false), commit("docs", false)];
+        let next = VersionIncrement::Auto.bump(&current, &commits, None).unwrap();
+        assert_eq!(next, Version::new(1, 2, 4));
+    }
+
+    #[test]
+    fn pre_release_identifier_is_applied() {
+        let current = Version::new(1, 0, 0);
+        let next = VersionIncrement::Minor
+            .bump(&current, &[], Some("rc.1"))
+            .unwrap();
+        assert_eq!(next, Version::parse("1.1.0-rc.1").unwrap());
+    }
+}
@@ -0,0 +1,7 @@
+/// Which git hook(s) `cog install-hook` should write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookKind {
+    PrepareCommit,
+    PrePush,
+    All,
+}
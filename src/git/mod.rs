@@ -0,0 +1,3 @@
+pub mod hook;
+pub mod revspec;
+pub mod tag;
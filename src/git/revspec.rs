@@ -0,0 +1,42 @@
+/// A user-provided commit range, e.g. `v1.0.0..v1.1.0` or `v1.0.0..`.
+#[derive(Debug, Clone, Default)]
+pub struct RevspecPattern(String);
+
+impl From<&str> for RevspecPattern {
+    fn from(value: &str) -> Self {
+        RevspecPattern(value.to_string())
+    }
+}
+
+impl RevspecPattern {
+    /// The left-hand side of a `from..to` range, if one was given.
+    pub fn from_tag(&self) -> Option<String> {
+        self.0
+            .split_once("..")
+            .map(|(from, _)| from.to_string())
+            .filter(|from| !from.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn extracts_the_lower_bound_of_a_range() {
+        let pattern = RevspecPattern::from("v1.0.0..v1.1.0");
+        assert_eq!(pattern.from_tag().as_deref(), Some("v1.0.0"));
+    }
+
+    #[test]
+    fn open_ended_range_has_a_lower_bound_only() {
+        let pattern = RevspecPattern::from("v1.0.0..");
+        assert_eq!(pattern.from_tag().as_deref(), Some("v1.0.0"));
+    }
+
+    #[test]
+    fn empty_pattern_has_no_lower_bound() {
+        let pattern = RevspecPattern::default();
+        assert_eq!(pattern.from_tag(), None);
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+
+use git2::Oid;
+use semver::Version;
+
+/// A parsed version tag. Global tags look like `v1.2.0`; monorepo package
+/// tags carry their package namespace, e.g. `foo-v1.2.0`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tag {
+    pub package: Option<String>,
+    pub version: Version,
+    pub oid: Option<Oid>,
+}
+
+impl Tag {
+    /// Parse a tag ref name (without the `refs/tags/` prefix).
+    pub fn parse(name: &str, oid: Oid) -> Option<Tag> {
+        let (package, version) = match name.rsplit_once("-v") {
+            Some((package, version)) => (Some(package.to_string()), version),
+            None => (None, name.strip_prefix('v')?),
+        };
+
+        let version = Version::parse(version).ok()?;
+        Some(Tag {
+            package,
+            version,
+            oid: Some(oid),
+        })
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.package {
+            Some(package) => write!(f, "{package}-v{}", self.version),
+            None => write!(f, "v{}", self.version),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_a_global_tag() {
+        let tag = Tag::parse("v1.2.0", Oid::zero()).unwrap();
+        assert_eq!(tag.package, None);
+        assert_eq!(tag.version, Version::new(1, 2, 0));
+        assert_eq!(tag.to_string(), "v1.2.0");
+    }
+
+    #[test]
+    fn parses_a_package_scoped_tag() {
+        let tag = Tag::parse("foo-v1.2.0", Oid::zero()).unwrap();
+        assert_eq!(tag.package.as_deref(), Some("foo"));
+        assert_eq!(tag.version, Version::new(1, 2, 0));
+        assert_eq!(tag.to_string(), "foo-v1.2.0");
+    }
+
+    #[test]
+    fn rejects_a_non_version_tag() {
+        assert!(Tag::parse("not-a-version", Oid::zero()).is_none());
+    }
+}
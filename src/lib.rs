@@ -0,0 +1,745 @@
+pub mod conventional;
+pub mod git;
+pub mod log;
+mod settings;
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use git2::Repository as Git2Repository;
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+
+pub use settings::Settings;
+
+use crate::conventional::changelog::template::Template;
+use crate::conventional::changelog::Changelog;
+use crate::conventional::commit::Commit;
+use crate::conventional::version::VersionIncrement;
+use crate::git::hook::HookKind;
+use crate::git::revspec::RevspecPattern;
+use crate::git::tag::Tag;
+use crate::log::filter::CommitFilters;
+use crate::settings::MonoRepoPackage;
+
+/// Global `cog.toml` configuration, lazily parsed on first access.
+pub static SETTINGS: Lazy<Settings> = Lazy::new(|| Settings::get().unwrap_or_default());
+
+/// Entry point into the library: wraps the git repository found in (or
+/// above) the current working directory.
+pub struct CocoGitto {
+    repository: Git2Repository,
+    /// `cog.toml` as found at the repository's root, independent of the
+    /// process' current directory.
+    settings: Settings,
+}
+
+impl CocoGitto {
+    /// Open the git repository rooted at or above the current directory.
+    pub fn get() -> Result<Self> {
+        let repository = Git2Repository::discover(".")
+            .context("failed to find a git repository in the current directory")?;
+        Self::from_repository(repository)
+    }
+
+    fn from_repository(repository: Git2Repository) -> Result<Self> {
+        let root = repository
+            .workdir()
+            .unwrap_or_else(|| repository.path())
+            .to_path_buf();
+        let settings = Settings::get_in(&root)?;
+        Ok(CocoGitto { repository, settings })
+    }
+
+    /// The committer name to attribute new commits to, taken from the git
+    /// config (`user.name`, falling back to `user.email`).
+    pub fn get_committer(&self) -> Result<String> {
+        let config = self.repository.config()?;
+        config
+            .get_string("user.name")
+            .or_else(|_| config.get_string("user.email"))
+            .context("git user.name or user.email must be set")
+    }
+
+    /// The highest-versioned global tag, if any. Whether it is reachable
+    /// from `HEAD` is not checked.
+    pub fn get_repo_tag_name(&self) -> Option<String> {
+        self.get_latest_tag(None).map(|tag| tag.to_string())
+    }
+
+    fn all_tags(&self) -> Result<Vec<Tag>> {
+        let mut tags = vec![];
+        self.repository.tag_foreach(|oid, name| {
+            if let Ok(name) = std::str::from_utf8(name) {
+                let name = name.trim_start_matches("refs/tags/");
+                if let Some(tag) = Tag::parse(name, oid) {
+                    tags.push(tag);
+                }
+            }
+            true
+        })?;
+        tags.sort_by(|a, b| a.version.cmp(&b.version));
+        Ok(tags)
+    }
+
+    /// Tags belonging to `package` (or the global namespace when `package`
+    /// is `None`), oldest first.
+    fn package_tags(&self, package: Option<&str>) -> Result<Vec<Tag>> {
+        Ok(self
+            .all_tags()?
+            .into_iter()
+            .filter(|tag| tag.package.as_deref() == package)
+            .collect())
+    }
+
+    fn get_latest_tag(&self, package: Option<&str>) -> Option<Tag> {
+        self.package_tags(package).ok()?.into_iter().last()
+    }
+
+    /// Commits between `from` (exclusive, the previous release) and `upto`
+    /// (inclusive, defaulting to `HEAD`) that belong to `package`. Merge
+    /// commits and commits that are not conventional commits are skipped.
+    /// When `package` is `None` every other commit in that range is
+    /// included; otherwise only commits that touch the package's declared
+    /// path, or carry its declared scope.
+    fn commits_for_package(
+        &self,
+        package: Option<&str>,
+        from: Option<&Tag>,
+        upto: Option<&Tag>,
+        regex: bool,
+    ) -> Result<Vec<Commit>> {
+        let mut revwalk = self.repository.revwalk()?;
+        match upto.and_then(|tag| tag.oid) {
+            Some(oid) => revwalk.push(oid)?,
+            None => revwalk.push_head()?,
+        }
+        if let Some(oid) = from.and_then(|tag| tag.oid) {
+            revwalk.hide(oid)?;
+        }
+
+        let package_config = package.map(|name| self.settings.get_package(name)).transpose()?;
+
+        let mut commits = vec![];
+        for oid in revwalk {
+            let oid = oid?;
+            let commit = self.repository.find_commit(oid)?;
+            if commit.parent_count() > 1 {
+                continue;
+            }
+
+            if let Some(config) = package_config {
+                if !self.commit_touches_package(&commit, config, regex)? {
+                    continue;
+                }
+            }
+
+            if let Ok(parsed) = Commit::try_from(&commit) {
+                commits.push(parsed);
+            }
+        }
+
+        Ok(commits)
+    }
+
+    fn commit_touches_package(
+        &self,
+        commit: &git2::Commit,
+        config: &MonoRepoPackage,
+        regex: bool,
+    ) -> Result<bool> {
+        if let Some(scope) = &config.scope {
+            let message = commit.message().unwrap_or_default();
+            let commit_scope = git_conventional::Commit::parse(message.trim())
+                .ok()
+                .and_then(|parsed| parsed.scope().map(|s| s.to_string()));
+
+            if let Some(commit_scope) = &commit_scope {
+                let matches = if regex {
+                    regex::Regex::new(scope)?.is_match(commit_scope)
+                } else {
+                    commit_scope == scope
+                };
+                if matches {
+                    return Ok(true);
+                }
+            }
+        }
+
+        let parent_tree = match commit.parent_count() {
+            0 => None,
+            _ => Some(commit.parent(0)?.tree()?),
+        };
+        let diff = self
+            .repository
+            .diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), None)?;
+
+        let prefix = Path::new(&config.path);
+        Ok(diff.deltas().any(|delta| {
+            delta
+                .new_file()
+                .path()
+                .or_else(|| delta.old_file().path())
+                .map(|path| path.starts_with(prefix))
+                .unwrap_or(false)
+        }))
+    }
+
+    /// Format the conventional commit history matching `filters`, one
+    /// commit per line.
+    pub fn get_log(&self, filters: CommitFilters) -> Result<String> {
+        let mut revwalk = self.repository.revwalk()?;
+        revwalk.push_head()?;
+
+        let mut out = String::new();
+        for oid in revwalk {
+            let oid = oid?;
+            let commit = self.repository.find_commit(oid)?;
+            let parsed = match Commit::try_from(&commit) {
+                Ok(commit) => commit,
+                Err(_) if filters.no_error() => continue,
+                Err(e) => return Err(e),
+            };
+
+            if filters.matches(&parsed) {
+                out.push_str(&parsed.to_string());
+                out.push('\n');
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Verify every commit since the last tag (or since the beginning of
+    /// history when `from_latest_tag` is false) is a valid conventional
+    /// commit.
+    pub fn check(&self, from_latest_tag: bool) -> Result<()> {
+        let from = if from_latest_tag {
+            self.get_latest_tag(None)
+        } else {
+            None
+        };
+
+        let mut revwalk = self.repository.revwalk()?;
+        revwalk.push_head()?;
+        if let Some(oid) = from.as_ref().and_then(|tag| tag.oid) {
+            revwalk.hide(oid)?;
+        }
+
+        let mut errors = vec![];
+        for oid in revwalk {
+            let oid = oid?;
+            let commit = self.repository.find_commit(oid)?;
+            if let Err(e) = Commit::try_from(&commit) {
+                errors.push(format!("{}: {e}", &oid.to_string()[..7]));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            bail!(errors.join("\n"))
+        }
+    }
+
+    /// Report non conventional commits exactly like [`check`](Self::check).
+    /// Commits are not renamed; rewriting history is left to the user's
+    /// editor of choice via `git rebase -i`.
+    pub fn check_and_edit(&self, from_latest_tag: bool) -> Result<()> {
+        self.check(from_latest_tag)
+    }
+
+    /// Create a conventional commit from its parts and write it to `HEAD`.
+    pub fn conventional_commit(
+        &self,
+        typ: &str,
+        scope: Option<String>,
+        summary: String,
+        body: Option<String>,
+        footer: Option<String>,
+        breaking: bool,
+    ) -> Result<()> {
+        let mut header = typ.to_string();
+        if let Some(scope) = &scope {
+            header.push_str(&format!("({scope})"));
+        }
+        if breaking {
+            header.push('!');
+        }
+        header.push_str(": ");
+        header.push_str(&summary);
+
+        conventional::commit::verify(self.get_committer().ok(), &header)?;
+
+        let mut message = header;
+        if let Some(body) = body {
+            message.push_str("\n\n");
+            message.push_str(&body);
+        }
+        if let Some(footer) = footer {
+            message.push_str("\n\n");
+            message.push_str(&footer);
+        }
+
+        let mut index = self.repository.index()?;
+        index.write()?;
+        let tree = self.repository.find_tree(index.write_tree()?)?;
+        let signature = self.repository.signature()?;
+        let parent = self.repository.head()?.peel_to_commit()?;
+
+        self.repository.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            &message,
+            &tree,
+            &[&parent],
+        )?;
+
+        Ok(())
+    }
+
+    /// Install the requested git hook(s), invoking `cog verify`/`cog check`
+    /// on the relevant git event.
+    pub fn install_hook(&self, kind: HookKind) -> Result<()> {
+        let hooks_dir = self.repository.path().join("hooks");
+        std::fs::create_dir_all(&hooks_dir)?;
+
+        let write_hook = |name: &str, script: &str| -> Result<()> {
+            let path = hooks_dir.join(name);
+            let mut file = File::create(&path)?;
+            file.write_all(script.as_bytes())?;
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                let mut perms = file.metadata()?.permissions();
+                perms.set_mode(0o755);
+                std::fs::set_permissions(&path, perms)?;
+            }
+            Ok(())
+        };
+
+        match kind {
+            HookKind::PrepareCommit => {
+                write_hook("commit-msg", "#!/bin/sh\ncog verify \"$(cat \"$1\")\"\n")
+            }
+            HookKind::PrePush => write_hook("pre-push", "#!/bin/sh\ncog check\n"),
+            HookKind::All => {
+                write_hook("commit-msg", "#!/bin/sh\ncog verify \"$(cat \"$1\")\"\n")?;
+                write_hook("pre-push", "#!/bin/sh\ncog check\n")
+            }
+        }
+    }
+
+    /// Compute the next version for `package` (or the whole repository when
+    /// `package` is `None`), tag it in the package's own namespace (e.g.
+    /// `foo-v1.2.0`) from just the commits that belong to it. Only the tag
+    /// is created: no changelog is written or committed, and
+    /// `hook_profile` is accepted but no hooks are run.
+    pub fn create_version(
+        &mut self,
+        increment: VersionIncrement,
+        pre: Option<&str>,
+        hook_profile: Option<&str>,
+        package: Option<&str>,
+    ) -> Result<()> {
+        let _ = hook_profile;
+
+        if let Some(name) = package {
+            self.settings.get_package(name)?;
+        }
+
+        let from = self.get_latest_tag(package);
+        let commits = self.commits_for_package(package, from.as_ref(), None, false)?;
+
+        let current = from
+            .as_ref()
+            .map(|tag| tag.version.clone())
+            .unwrap_or_else(|| semver::Version::new(0, 0, 0));
+
+        let next = increment.bump(&current, &commits, pre)?;
+        let tag = Tag {
+            package: package.map(str::to_owned),
+            version: next,
+            oid: None,
+        };
+
+        let head = self.repository.head()?.peel_to_commit()?;
+        self.repository
+            .tag_lightweight(&tag.to_string(), head.as_object(), false)
+            .with_context(|| format!("failed to create tag {tag}"))?;
+
+        Ok(())
+    }
+
+    fn tag_date(&self, tag: &Tag) -> Result<chrono::NaiveDate> {
+        let oid = tag.oid.context("tag has no associated commit")?;
+        let commit = self.repository.find_commit(oid)?;
+        chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
+            .map(|dt| dt.date_naive())
+            .context("tag's commit has an invalid timestamp")
+    }
+
+    /// Collect the changelog section for `pattern`, optionally scoped to
+    /// `package` and matching its declared scope as a regular expression.
+    /// The returned [`Changelog`] is rendered with
+    /// [`Changelog::into_markdown`].
+    pub fn get_changelog(
+        &self,
+        pattern: RevspecPattern,
+        with_child_releases: bool,
+        package: Option<&str>,
+        regex: bool,
+    ) -> Result<Changelog> {
+        let _ = with_child_releases;
+        let tags = self.package_tags(package)?;
+        let from = pattern
+            .from_tag()
+            .and_then(|name| tags.iter().find(|tag| tag.to_string() == name).cloned());
+
+        let commits = self.commits_for_package(package, from.as_ref(), None, regex)?;
+        Ok(Changelog {
+            from: from.map(|tag| tag.to_string()),
+            to: "HEAD".to_string(),
+            date: chrono::Utc::now().date_naive(),
+            commits,
+        })
+    }
+
+    /// Render the changelog for the single release tagged `tag_name`,
+    /// optionally re-scoped to `package` and matching its declared scope as
+    /// a regular expression. `package` is normally redundant with the tag's
+    /// own namespace, but is accepted so `--package`/`--regex` behave here
+    /// as they do for a range instead of being silently dropped.
+    pub fn get_changelog_at_tag(
+        &self,
+        tag_name: &str,
+        template: Template,
+        package: Option<&str>,
+        regex: bool,
+    ) -> Result<String> {
+        let named_package = Tag::parse(tag_name, git2::Oid::zero())
+            .with_context(|| format!("{tag_name} is not a valid version tag"))?
+            .package;
+        let package = package.or(named_package.as_deref());
+
+        let tags = self.package_tags(package)?;
+        let idx = tags
+            .iter()
+            .position(|tag| tag.to_string() == tag_name)
+            .with_context(|| format!("tag {tag_name} not found"))?;
+        let previous = idx.checked_sub(1).map(|i| &tags[i]);
+        let tag = &tags[idx];
+
+        let commits = self.commits_for_package(package, previous, Some(tag), regex)?;
+        let changelog = Changelog {
+            from: previous.map(|tag| tag.to_string()),
+            to: tag.to_string(),
+            date: self.tag_date(tag)?,
+            commits,
+        };
+
+        changelog.into_markdown(template)
+    }
+
+    /// Walk the entire tag history for `package` and render one changelog
+    /// section per release, newest first, with an "Unreleased" section on
+    /// top for commits since the latest tag. The package's declared scope is
+    /// matched literally in this mode, never as a regular expression.
+    pub fn get_changelog_for_all_tags(
+        &self,
+        template: Template,
+        package: Option<&str>,
+    ) -> Result<String> {
+        let tags = self.package_tags(package)?;
+
+        let mut sections: IndexMap<Tag, (Option<Tag>, Vec<Commit>)> = IndexMap::new();
+        let mut previous: Option<Tag> = None;
+        for tag in &tags {
+            let commits = self.commits_for_package(package, previous.as_ref(), Some(tag), false)?;
+            sections.insert(tag.clone(), (previous.clone(), commits));
+            previous = Some(tag.clone());
+        }
+
+        let unreleased = self.commits_for_package(package, previous.as_ref(), None, false)?;
+
+        let mut rendered = vec![];
+        if !unreleased.is_empty() {
+            let changelog = Changelog {
+                from: previous.as_ref().map(|tag| tag.to_string()),
+                to: "HEAD".to_string(),
+                date: chrono::Utc::now().date_naive(),
+                commits: unreleased,
+            };
+            rendered.push(changelog.into_markdown(template.clone())?);
+        }
+
+        for (tag, (from, commits)) in sections.into_iter().rev() {
+            let changelog = Changelog {
+                from: from.map(|tag| tag.to_string()),
+                date: self.tag_date(&tag)?,
+                to: tag.to_string(),
+                commits,
+            };
+            rendered.push(changelog.into_markdown(template.clone())?);
+        }
+
+        Ok(rendered.join("\n\n"))
+    }
+}
+
+/// Write the default `cog.toml` at `path`.
+pub fn init(path: &Path) -> Result<()> {
+    let config_path = path.join("cog.toml");
+    if config_path.exists() {
+        return Ok(());
+    }
+    let mut file = File::create(&config_path)
+        .with_context(|| format!("failed to create {}", config_path.display()))?;
+    file.write_all(toml::to_string_pretty(&Settings::default())?.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use git2::Signature;
+    use std::fs;
+    use tempfile::TempDir;
+
+    struct TestRepo {
+        _dir: TempDir,
+        cocogitto: CocoGitto,
+    }
+
+    fn init_repo() -> TestRepo {
+        let dir = TempDir::new().unwrap();
+        let repository = Git2Repository::init(dir.path()).unwrap();
+        {
+            let mut config = repository.config().unwrap();
+            config.set_str("user.name", "test").unwrap();
+            config.set_str("user.email", "test@example.com").unwrap();
+        }
+        TestRepo {
+            _dir: dir,
+            cocogitto: CocoGitto::from_repository(repository).unwrap(),
+        }
+    }
+
+    fn commit(repo: &TestRepo, message: &str) -> git2::Oid {
+        let repository = &repo.cocogitto.repository;
+        let signature = Signature::now("test", "test@example.com").unwrap();
+        let mut index = repository.index().unwrap();
+        let tree = repository.find_tree(index.write_tree().unwrap()).unwrap();
+
+        let parents: Vec<git2::Commit> = repository
+            .head()
+            .ok()
+            .and_then(|head| head.peel_to_commit().ok())
+            .into_iter()
+            .collect();
+        let parents_ref: Vec<&git2::Commit> = parents.iter().collect();
+
+        repository
+            .commit(Some("HEAD"), &signature, &signature, message, &tree, &parents_ref)
+            .unwrap()
+    }
+
+    fn tag(repo: &TestRepo, name: &str) {
+        let repository = &repo.cocogitto.repository;
+        let head = repository.head().unwrap().peel_to_commit().unwrap();
+        repository
+            .tag_lightweight(name, head.as_object(), false)
+            .unwrap();
+    }
+
+    #[test]
+    fn bumps_the_first_version_from_a_feature_commit() {
+        let repo = init_repo();
+        commit(&repo, "feat: add a first feature");
+
+        let mut cocogitto = repo.cocogitto;
+        cocogitto
+            .create_version(VersionIncrement::Auto, None, None, None)
+            .unwrap();
+
+        assert_eq!(cocogitto.get_repo_tag_name().as_deref(), Some("v0.1.0"));
+    }
+
+    #[test]
+    fn monorepo_package_gets_its_own_tag_namespace() {
+        let repo = init_repo();
+        fs::write(repo._dir.path().join("cog.toml"), "[packages.foo]\npath = \"foo\"\n").unwrap();
+        fs::create_dir(repo._dir.path().join("foo")).unwrap();
+        fs::write(repo._dir.path().join("foo/file.txt"), "hello").unwrap();
+
+        let repository = &repo.cocogitto.repository;
+        let mut index = repository.index().unwrap();
+        index.add_path(Path::new("foo/file.txt")).unwrap();
+        index.write().unwrap();
+        drop(index);
+        commit(&repo, "feat(foo): add the foo package");
+
+        let cwd = std::env::current_dir().unwrap();
+        std::env::set_current_dir(repo._dir.path()).unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut cocogitto = CocoGitto::get().unwrap();
+            cocogitto
+                .create_version(VersionIncrement::Auto, None, None, Some("foo"))
+                .unwrap();
+            cocogitto
+        }));
+        std::env::set_current_dir(cwd).unwrap();
+
+        let cocogitto = result.unwrap();
+        let tags = cocogitto.all_tags().unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].to_string(), "foo-v0.1.0");
+    }
+
+    #[test]
+    fn bump_rejects_an_unknown_package() {
+        let repo = init_repo();
+        commit(&repo, "feat: add a first feature");
+
+        let mut cocogitto = repo.cocogitto;
+        let result = cocogitto.create_version(VersionIncrement::Auto, None, None, Some("missing"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn renders_a_changelog_for_a_single_tag() {
+        let repo = init_repo();
+        commit(&repo, "feat: add a first feature");
+        tag(&repo, "v0.1.0");
+        commit(&repo, "fix: fix a bug");
+        tag(&repo, "v0.1.1");
+
+        let markdown = repo
+            .cocogitto
+            .get_changelog_at_tag("v0.1.1", Template::default(), None, false)
+            .unwrap();
+
+        assert!(markdown.contains("v0.1.1"));
+        assert!(markdown.contains("fix a bug"));
+        assert!(!markdown.contains("add a first feature"));
+    }
+
+    #[test]
+    fn changelog_at_a_global_tag_compares_against_the_previous_global_tag() {
+        use crate::conventional::changelog::template::RemoteContext;
+
+        let repo = init_repo();
+        fs::write(repo._dir.path().join("cog.toml"), "[packages.foo]\npath = \"foo\"\n").unwrap();
+
+        commit(&repo, "feat: add a first feature");
+        tag(&repo, "v1.0.0");
+        commit(&repo, "feat(foo): add the foo package");
+        tag(&repo, "foo-v1.5.0");
+        commit(&repo, "feat: add a second feature");
+        tag(&repo, "v2.0.0");
+
+        let template = Template::from_arg(
+            "remote",
+            Some(RemoteContext::new(
+                "https://github.com".to_string(),
+                "cocogitto".to_string(),
+                "oknozor".to_string(),
+            )),
+        )
+        .unwrap();
+
+        let markdown = repo
+            .cocogitto
+            .get_changelog_at_tag("v2.0.0", template, None, false)
+            .unwrap();
+
+        assert!(markdown.contains("compare/v1.0.0...v2.0.0"));
+        assert!(!markdown.contains("foo-v1.5.0"));
+    }
+
+    #[test]
+    fn package_scope_matches_as_a_regular_expression_when_enabled() {
+        let repo = init_repo();
+        fs::write(
+            repo._dir.path().join("cog.toml"),
+            "[packages.api]\npath = \"api\"\nscope = \"^api-.*\"\n",
+        )
+        .unwrap();
+        commit(&repo, "feat(api-users): add users endpoint");
+        commit(&repo, "feat(web): unrelated change");
+
+        let cwd = std::env::current_dir().unwrap();
+        std::env::set_current_dir(repo._dir.path()).unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let cocogitto = CocoGitto::get().unwrap();
+            let literal = cocogitto
+                .commits_for_package(Some("api"), None, None, false)
+                .unwrap();
+            let regex = cocogitto
+                .commits_for_package(Some("api"), None, None, true)
+                .unwrap();
+            (literal.len(), regex.len())
+        }));
+        std::env::set_current_dir(cwd).unwrap();
+
+        let (literal_matches, regex_matches) = result.unwrap();
+        assert_eq!(literal_matches, 0, "a literal scope `^api-.*` matches no commit scope");
+        assert_eq!(regex_matches, 1, "the regex scope should match `api-users`");
+    }
+
+    #[test]
+    fn root_commit_with_no_parent_is_diffed_against_an_empty_tree() {
+        let repo = init_repo();
+        fs::write(repo._dir.path().join("cog.toml"), "[packages.foo]\npath = \"foo\"\n").unwrap();
+        fs::create_dir(repo._dir.path().join("other")).unwrap();
+        fs::write(repo._dir.path().join("other/file.txt"), "hello").unwrap();
+
+        let repository = &repo.cocogitto.repository;
+        let mut index = repository.index().unwrap();
+        index.add_path(Path::new("other/file.txt")).unwrap();
+        index.write().unwrap();
+        drop(index);
+        commit(&repo, "chore: root commit outside the package");
+
+        let cwd = std::env::current_dir().unwrap();
+        std::env::set_current_dir(repo._dir.path()).unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let cocogitto = CocoGitto::get().unwrap();
+            cocogitto
+                .commits_for_package(Some("foo"), None, None, false)
+                .unwrap()
+                .len()
+        }));
+        std::env::set_current_dir(cwd).unwrap();
+
+        assert_eq!(
+            result.unwrap(),
+            0,
+            "a root commit that doesn't touch the package's path shouldn't be attributed to it"
+        );
+    }
+
+    #[test]
+    fn all_tags_changelog_puts_every_commit_in_exactly_one_bucket() {
+        let repo = init_repo();
+        commit(&repo, "feat: add a first feature");
+        tag(&repo, "v0.1.0");
+        commit(&repo, "fix: fix a bug");
+        tag(&repo, "v0.1.1");
+        commit(&repo, "feat: add a second feature");
+
+        let markdown = repo
+            .cocogitto
+            .get_changelog_for_all_tags(Template::default(), None)
+            .unwrap();
+
+        assert_eq!(markdown.matches("add a first feature").count(), 1);
+        assert_eq!(markdown.matches("fix a bug").count(), 1);
+        assert_eq!(markdown.matches("add a second feature").count(), 1);
+        assert!(markdown.contains("v0.1.0"));
+        assert!(markdown.contains("v0.1.1"));
+    }
+}
@@ -0,0 +1,147 @@
+use regex::Regex;
+
+use crate::conventional::commit::Commit;
+
+/// A single criterion used to narrow down `cog log` output.
+/// The `*Regex` variants match a compiled pattern instead of requiring an
+/// exact string, enabled by the `--regex`/`-E` flag.
+#[derive(Debug)]
+pub enum CommitFilter {
+    Type(crate::conventional::commit::CommitType),
+    TypeRegex(Regex),
+    Scope(String),
+    ScopeRegex(Regex),
+    Author(String),
+    AuthorRegex(Regex),
+    BreakingChange,
+    NoError,
+}
+
+impl CommitFilter {
+    fn matches(&self, commit: &Commit) -> bool {
+        match self {
+            CommitFilter::Type(commit_type) => &commit.commit_type == commit_type,
+            CommitFilter::TypeRegex(re) => re.is_match(commit.commit_type.as_str()),
+            CommitFilter::Scope(scope) => commit.scope.as_deref() == Some(scope.as_str()),
+            CommitFilter::ScopeRegex(re) => commit
+                .scope
+                .as_deref()
+                .map(|scope| re.is_match(scope))
+                .unwrap_or(false),
+            CommitFilter::Author(author) => &commit.author == author,
+            CommitFilter::AuthorRegex(re) => re.is_match(&commit.author),
+            CommitFilter::BreakingChange => commit.breaking_change,
+            CommitFilter::NoError => true,
+        }
+    }
+}
+
+/// A set of filters built from CLI flags. Filters targeting the same kind
+/// (e.g. two `--type` values) are OR-ed together; different kinds are
+/// AND-ed, matching `cog log --type feat --type fix --author jane`
+/// ("feat or fix commits, by jane").
+pub struct CommitFilters(pub Vec<CommitFilter>);
+
+impl CommitFilters {
+    pub fn no_error(&self) -> bool {
+        self.0.iter().any(|filter| matches!(filter, CommitFilter::NoError))
+    }
+
+    pub fn matches(&self, commit: &Commit) -> bool {
+        let mut types = vec![];
+        let mut scopes = vec![];
+        let mut authors = vec![];
+        let mut breaking_change = false;
+
+        for filter in &self.0 {
+            match filter {
+                CommitFilter::Type(_) | CommitFilter::TypeRegex(_) => types.push(filter),
+                CommitFilter::Scope(_) | CommitFilter::ScopeRegex(_) => scopes.push(filter),
+                CommitFilter::Author(_) | CommitFilter::AuthorRegex(_) => authors.push(filter),
+                CommitFilter::BreakingChange => breaking_change = true,
+                CommitFilter::NoError => {}
+            }
+        }
+
+        let types_match = types.is_empty() || types.iter().any(|filter| filter.matches(commit));
+        let scopes_match = scopes.is_empty() || scopes.iter().any(|filter| filter.matches(commit));
+        let authors_match = authors.is_empty() || authors.iter().any(|filter| filter.matches(commit));
+        let breaking_match = !breaking_change || commit.breaking_change;
+
+        types_match && scopes_match && authors_match && breaking_match
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn commit(commit_type: &str, scope: Option<&str>, author: &str, breaking: bool) -> Commit {
+        Commit {
+            oid: git2::Oid::zero(),
+            author: author.to_string(),
+            date: chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            commit_type: commit_type.into(),
+            scope: scope.map(str::to_string),
+            summary: "test commit".to_string(),
+            body: None,
+            breaking_change: breaking,
+        }
+    }
+
+    #[test]
+    fn empty_filter_set_matches_everything() {
+        let filters = CommitFilters(vec![]);
+        assert!(filters.matches(&commit("feat", None, "jane", false)));
+    }
+
+    #[test]
+    fn type_filter_matches_literally() {
+        let filters = CommitFilters(vec![CommitFilter::Type("feat".into())]);
+        assert!(filters.matches(&commit("feat", None, "jane", false)));
+        assert!(!filters.matches(&commit("fix", None, "jane", false)));
+    }
+
+    #[test]
+    fn scope_regex_matches_a_pattern() {
+        let filters = CommitFilters(vec![CommitFilter::ScopeRegex(Regex::new("^api-.*").unwrap())]);
+        assert!(filters.matches(&commit("feat", Some("api-users"), "jane", false)));
+        assert!(!filters.matches(&commit("feat", Some("web-users"), "jane", false)));
+        assert!(!filters.matches(&commit("feat", None, "jane", false)));
+    }
+
+    #[test]
+    fn author_regex_matches_a_pattern() {
+        let filters = CommitFilters(vec![CommitFilter::AuthorRegex(Regex::new("^jane").unwrap())]);
+        assert!(filters.matches(&commit("feat", None, "jane doe", false)));
+        assert!(!filters.matches(&commit("feat", None, "john doe", false)));
+    }
+
+    #[test]
+    fn same_kind_filters_are_combined_with_or() {
+        let filters = CommitFilters(vec![
+            CommitFilter::Type("feat".into()),
+            CommitFilter::Type("fix".into()),
+        ]);
+        assert!(filters.matches(&commit("feat", None, "jane", false)));
+        assert!(filters.matches(&commit("fix", None, "jane", false)));
+        assert!(!filters.matches(&commit("chore", None, "jane", false)));
+    }
+
+    #[test]
+    fn different_kind_filters_are_combined_with_and() {
+        let filters = CommitFilters(vec![
+            CommitFilter::Type("feat".into()),
+            CommitFilter::Author("jane".to_string()),
+        ]);
+        assert!(filters.matches(&commit("feat", None, "jane", false)));
+        assert!(!filters.matches(&commit("feat", None, "john", false)));
+    }
+
+    #[test]
+    fn breaking_change_filter_excludes_non_breaking_commits() {
+        let filters = CommitFilters(vec![CommitFilter::BreakingChange]);
+        assert!(filters.matches(&commit("feat", None, "jane", true)));
+        assert!(!filters.matches(&commit("feat", None, "jane", false)));
+    }
+}
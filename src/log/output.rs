@@ -0,0 +1,76 @@
+use std::env;
+use std::io::{self, Write};
+use std::process::{Child, Command, Stdio};
+
+use anyhow::Result;
+
+/// Builds an [`Output`], optionally piping through a pager process.
+pub struct OutputBuilder {
+    pager: Option<String>,
+    file_name: String,
+}
+
+impl OutputBuilder {
+    /// Pipe the output through the pager named by the `var` environment
+    /// variable, if it is set.
+    pub fn with_pager_from_env(mut self, var: &str) -> Self {
+        self.pager = env::var(var).ok().filter(|pager| !pager.is_empty());
+        self
+    }
+
+    /// Title set on the terminal window when output goes to stdout; unused
+    /// when a pager is spawned.
+    pub fn with_file_name(mut self, name: &str) -> Self {
+        self.file_name = name.to_string();
+        self
+    }
+
+    pub fn build(self) -> Result<Output> {
+        let child = self
+            .pager
+            .as_deref()
+            .and_then(|pager| Command::new(pager).stdin(Stdio::piped()).spawn().ok());
+
+        Ok(Output {
+            child,
+            file_name: self.file_name,
+        })
+    }
+}
+
+/// Where `cog log` writes its rendered output: a spawned pager when one is
+/// configured, stdout otherwise.
+pub struct Output {
+    child: Option<Child>,
+    file_name: String,
+}
+
+impl Output {
+    pub fn builder() -> OutputBuilder {
+        OutputBuilder {
+            pager: None,
+            file_name: "cog log".to_string(),
+        }
+    }
+
+    pub fn handle(&mut self) -> Result<Box<dyn Write + '_>> {
+        match &mut self.child {
+            Some(child) => Ok(Box::new(
+                child.stdin.take().expect("pager stdin was already taken"),
+            )),
+            None => {
+                let mut stdout = io::stdout();
+                let _ = write!(stdout, "\x1b]2;{}\x07", self.file_name);
+                Ok(Box::new(stdout))
+            }
+        }
+    }
+}
+
+impl Drop for Output {
+    fn drop(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            let _ = child.wait();
+        }
+    }
+}
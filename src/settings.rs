@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::conventional::changelog::template::Template;
+
+pub(crate) const SETTINGS_FILE_NAME: &str = "cog.toml";
+
+/// A monorepo subproject declared in `cog.toml`. A commit belongs to the
+/// package when it touches a file under `path`, or carries `scope` as its
+/// conventional commit scope. The package gets its own `<name>-v*` tag
+/// namespace, independent of the repository's global tags.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(deny_unknown_fields, default)]
+pub struct MonoRepoPackage {
+    pub path: String,
+    pub scope: Option<String>,
+}
+
+/// Default changelog rendering options, overridable per `cog changelog`
+/// invocation.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields, default)]
+pub struct ChangelogSettings {
+    pub template: Option<String>,
+}
+
+/// Hooks run before/after a `cog bump`, selectable with `--hook-profile`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields, default)]
+pub struct BumpProfile {
+    pub pre_bump_hooks: Vec<String>,
+    pub post_bump_hooks: Vec<String>,
+}
+
+/// Parsed `cog.toml`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields, default)]
+pub struct Settings {
+    pub changelog: ChangelogSettings,
+    pub bump_profiles: HashMap<String, BumpProfile>,
+    pub packages: HashMap<String, MonoRepoPackage>,
+}
+
+impl Settings {
+    /// Load `cog.toml` from the current directory, falling back to
+    /// defaults when it doesn't exist.
+    pub fn get() -> Result<Settings> {
+        Settings::get_in(Path::new("."))
+    }
+
+    /// Load `<dir>/cog.toml`, falling back to defaults when it doesn't
+    /// exist. Used to load settings relative to a repository's root rather
+    /// than the process' current directory.
+    pub fn get_in(dir: &Path) -> Result<Settings> {
+        match fs::read_to_string(dir.join(SETTINGS_FILE_NAME)) {
+            Ok(content) => toml::from_str(&content)
+                .with_context(|| format!("failed to parse {SETTINGS_FILE_NAME}")),
+            Err(_) => Ok(Settings::default()),
+        }
+    }
+
+    /// Look up a declared monorepo package by name.
+    pub fn get_package(&self, name: &str) -> Result<&MonoRepoPackage> {
+        self.packages.get(name).ok_or_else(|| {
+            anyhow!(
+                "package `{name}` is not declared in {SETTINGS_FILE_NAME}, known packages: {:?}",
+                self.packages.keys().collect::<Vec<_>>()
+            )
+        })
+    }
+
+    pub fn to_changelog_template(&self) -> Option<Template> {
+        self.changelog
+            .template
+            .as_deref()
+            .and_then(|arg| Template::from_arg(arg, None).ok())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_monorepo_packages() {
+        let toml = r#"
+            [packages.foo]
+            path = "foo"
+            scope = "foo"
+
+            [packages.bar]
+            path = "bar"
+        "#;
+
+        let settings: Settings = toml::from_str(toml).unwrap();
+
+        assert_eq!(
+            settings.get_package("foo").unwrap(),
+            &MonoRepoPackage {
+                path: "foo".to_string(),
+                scope: Some("foo".to_string()),
+            }
+        );
+        assert_eq!(
+            settings.get_package("bar").unwrap(),
+            &MonoRepoPackage {
+                path: "bar".to_string(),
+                scope: None,
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_package_is_an_error() {
+        let settings = Settings::default();
+        assert!(settings.get_package("does-not-exist").is_err());
+    }
+}